@@ -2,10 +2,13 @@ use crate::utils::*;
 use burn::prelude::*;
 use hsl::HSL;
 use plotters::{
+    backend::{BackendColor, DrawingBackend, DrawingErrorKind},
+    coord::Shift,
     prelude::*,
     style::text_anchor::{HPos, Pos, VPos},
 };
 use std::collections::HashSet;
+use std::fmt;
 
 /// The default caption for the chart
 const CAPTION: &str = "fast-umap";
@@ -13,13 +16,80 @@ const CAPTION: &str = "fast-umap";
 /// The default path where the plot will be saved
 const PATH: &str = "plot.png";
 
-/// Configuration structure for the chart, including caption, path, width, and height
+/// Where a chart's drawing output ends up.
+///
+/// `Png` and `Svg` write to a file path on disk, while `SvgString` renders to
+/// an in-memory buffer and hands back the serialized markup instead of
+/// touching the filesystem, which is what callers embedding plots in
+/// notebooks, web UIs, or WASM builds need.
+#[derive(Debug, Clone)]
+pub enum OutputFormat {
+    /// Rasterize the chart to a PNG file at the given path.
+    Png(String),
+    /// Render the chart to a vector SVG file at the given path.
+    Svg(String),
+    /// Render the chart to SVG and return the markup as a `String`.
+    SvgString,
+}
+
+impl Default for OutputFormat {
+    fn default() -> Self {
+        OutputFormat::Png(PATH.to_string())
+    }
+}
+
+/// How `chart_vector`/`chart_tensor` render the data points.
+#[derive(Debug, Clone)]
+pub enum RenderMode {
+    /// Draw each point as an outlined circle, colored by label.
+    Scatter,
+    /// Bin points into a `resolution x resolution` grid and fill each
+    /// occupied cell with a color scaled by point count, instead of drawing
+    /// individual points. Keeps large datasets legible and cheap to draw,
+    /// where per-point circles would overdraw into an unreadable blob.
+    Density { resolution: usize },
+}
+
+impl Default for RenderMode {
+    fn default() -> Self {
+        RenderMode::Scatter
+    }
+}
+
+/// Default yaw (radians) for the 3D camera projection
+const YAW_3D: f64 = 0.5;
+
+/// Default pitch (radians) for the 3D camera projection
+const PITCH_3D: f64 = 0.3;
+
+/// Default scale for the 3D camera projection
+const SCALE_3D: f64 = 0.9;
+
+/// Default number of terminal columns used by `chart_vector_console`
+const CONSOLE_WIDTH: u32 = 80;
+
+/// Default number of terminal rows used by `chart_vector_console`
+const CONSOLE_HEIGHT: u32 = 24;
+
+/// Configuration structure for the chart, including caption, output, width, and height
 #[derive(Debug, Clone)]
 pub struct ChartConfig {
     pub caption: String,
-    pub path: String,
+    pub output: OutputFormat,
     pub width: u32,
     pub height: u32,
+    /// Yaw (radians) of the camera used by `chart_vector_3d`/`chart_tensor_3d`
+    pub yaw_3d: f64,
+    /// Pitch (radians) of the camera used by `chart_vector_3d`/`chart_tensor_3d`
+    pub pitch_3d: f64,
+    /// Scale of the camera used by `chart_vector_3d`/`chart_tensor_3d`
+    pub scale_3d: f64,
+    /// Terminal columns used by `chart_vector_console`
+    pub console_width: u32,
+    /// Terminal rows used by `chart_vector_console`
+    pub console_height: u32,
+    /// How data points are rendered (per-point scatter, or binned density)
+    pub mode: RenderMode,
 }
 
 impl ChartConfig {
@@ -27,9 +97,15 @@ impl ChartConfig {
     pub fn builder() -> ChartConfigBuilder {
         ChartConfigBuilder {
             caption: Some(CAPTION.to_string()),
-            path: Some(PATH.to_string()),
+            output: Some(OutputFormat::default()),
             width: Some(1000),
             height: Some(1000),
+            yaw_3d: Some(YAW_3D),
+            pitch_3d: Some(PITCH_3D),
+            scale_3d: Some(SCALE_3D),
+            console_width: Some(CONSOLE_WIDTH),
+            console_height: Some(CONSOLE_HEIGHT),
+            mode: Some(RenderMode::default()),
         }
     }
 }
@@ -39,9 +115,15 @@ impl Default for ChartConfig {
     fn default() -> Self {
         ChartConfig {
             caption: CAPTION.to_string(),
-            path: PATH.to_string(),
+            output: OutputFormat::default(),
             width: 1000,
             height: 1000,
+            yaw_3d: YAW_3D,
+            pitch_3d: PITCH_3D,
+            scale_3d: SCALE_3D,
+            console_width: CONSOLE_WIDTH,
+            console_height: CONSOLE_HEIGHT,
+            mode: RenderMode::default(),
         }
     }
 }
@@ -49,18 +131,30 @@ impl Default for ChartConfig {
 /// Builder pattern for `ChartConfig` struct to allow flexible configuration
 pub struct ChartConfigBuilder {
     caption: Option<String>,
-    path: Option<String>,
+    output: Option<OutputFormat>,
     width: Option<u32>,
     height: Option<u32>,
+    yaw_3d: Option<f64>,
+    pitch_3d: Option<f64>,
+    scale_3d: Option<f64>,
+    console_width: Option<u32>,
+    console_height: Option<u32>,
+    mode: Option<RenderMode>,
 }
 
 impl Default for ChartConfigBuilder {
     fn default() -> Self {
         ChartConfigBuilder {
             caption: Some(CAPTION.into()),
-            path: Some(PATH.into()),
+            output: Some(OutputFormat::default()),
             width: None,
             height: None,
+            yaw_3d: Some(YAW_3D),
+            pitch_3d: Some(PITCH_3D),
+            scale_3d: Some(SCALE_3D),
+            console_width: Some(CONSOLE_WIDTH),
+            console_height: Some(CONSOLE_HEIGHT),
+            mode: Some(RenderMode::default()),
         }
     }
 }
@@ -72,9 +166,27 @@ impl ChartConfigBuilder {
         self
     }
 
-    /// Set the path where the chart will be saved
+    /// Set the path where the chart will be saved as a PNG
     pub fn path(mut self, path: &str) -> Self {
-        self.path = Some(path.to_string());
+        self.output = Some(OutputFormat::Png(path.to_string()));
+        self
+    }
+
+    /// Render the chart as an SVG file at the given path
+    pub fn svg(mut self, path: &str) -> Self {
+        self.output = Some(OutputFormat::Svg(path.to_string()));
+        self
+    }
+
+    /// Render the chart as SVG and return the markup instead of writing a file
+    pub fn svg_string(mut self) -> Self {
+        self.output = Some(OutputFormat::SvgString);
+        self
+    }
+
+    /// Set the output format directly
+    pub fn output(mut self, output: OutputFormat) -> Self {
+        self.output = Some(output);
         self
     }
 
@@ -90,13 +202,47 @@ impl ChartConfigBuilder {
         self
     }
 
+    /// Set the camera projection (yaw, pitch, scale) used by the 3D chart functions
+    pub fn projection_3d(mut self, yaw: f64, pitch: f64, scale: f64) -> Self {
+        self.yaw_3d = Some(yaw);
+        self.pitch_3d = Some(pitch);
+        self.scale_3d = Some(scale);
+        self
+    }
+
+    /// Set the terminal size (columns, rows) used by `chart_vector_console`
+    pub fn console_size(mut self, width: u32, height: u32) -> Self {
+        self.console_width = Some(width);
+        self.console_height = Some(height);
+        self
+    }
+
+    /// Render data points as per-point circles, colored by label (the default)
+    pub fn scatter(mut self) -> Self {
+        self.mode = Some(RenderMode::Scatter);
+        self
+    }
+
+    /// Render data points binned into a `resolution x resolution` density grid
+    /// instead of per-point circles, to keep large datasets legible
+    pub fn density(mut self, resolution: usize) -> Self {
+        self.mode = Some(RenderMode::Density { resolution });
+        self
+    }
+
     /// Build and return the final `ChartConfig`
     pub fn build(self) -> ChartConfig {
         ChartConfig {
             caption: self.caption.unwrap_or_else(|| CAPTION.to_string()),
-            path: self.path.unwrap_or_else(|| PATH.to_string()),
+            output: self.output.unwrap_or_default(),
             width: self.width.unwrap_or(1000),
             height: self.height.unwrap_or(1000),
+            yaw_3d: self.yaw_3d.unwrap_or(YAW_3D),
+            pitch_3d: self.pitch_3d.unwrap_or(PITCH_3D),
+            scale_3d: self.scale_3d.unwrap_or(SCALE_3D),
+            console_width: self.console_width.unwrap_or(CONSOLE_WIDTH),
+            console_height: self.console_height.unwrap_or(CONSOLE_HEIGHT),
+            mode: self.mode.unwrap_or_default(),
         }
     }
 }
@@ -108,25 +254,26 @@ type Float = f64;
 /// # Arguments
 /// * `data` - A 2D tensor of data points to plot
 /// * `config` - Optional custom chart configuration
+///
+/// Returns `Some(svg)` when `config.output` is `OutputFormat::SvgString`, `None` otherwise.
 pub fn chart_tensor<B: Backend>(
     data: Tensor<B, 2>,
     labels: Option<Vec<String>>,
     config: Option<ChartConfig>,
-) {
-    // pub fn chart_tensor<B: Backend>(data: Tensor<B, 2>, config: Option<ChartConfig>) {
+) -> Option<String> {
     let data: Vec<Vec<Float>> = convert_tensor_to_vector(data);
-    chart_vector(data, labels, config);
+    chart_vector(data, labels, config)
 }
 
-/// Plot the loss curve over epochs and save it to a file
+/// Plot the loss curve over epochs and save it to the given output
 ///
 /// # Arguments
 /// * `losses` - A vector of loss values over multiple epochs
-/// * `output_path` - Path where the plot will be saved
+/// * `output` - Where to render the plot (PNG file, SVG file, or SVG string)
 pub fn plot_loss<F: num::Float>(
     losses: Vec<F>,
-    output_path: &str,
-) -> Result<(), Box<dyn std::error::Error>>
+    output: OutputFormat,
+) -> Result<Option<String>, Box<dyn std::error::Error>>
 where
     F:,
 {
@@ -141,81 +288,128 @@ where
     let min_loss_with_padding = min_loss_with_padding.to_f64().unwrap();
     let max_loss_with_padding = max_loss_with_padding.to_f64().unwrap();
 
-    // Create a drawing area with a width of 800px and a height of 600px
-    let root = BitMapBackend::new(output_path, (800, 600)).into_drawing_area();
-    root.fill(&WHITE)?;
+    fn draw_loss<DB: DrawingBackend>(
+        root: DrawingArea<DB, Shift>,
+        losses: &[f64],
+        min_loss: f64,
+        max_loss: f64,
+    ) -> Result<(), Box<dyn std::error::Error>>
+    where
+        DB::ErrorType: 'static,
+    {
+        root.fill(&WHITE)?;
 
-    // Create a chart builder with padded Y-axis range
-    let mut chart = ChartBuilder::on(&root)
-        .caption("Loss Over Epochs", ("sans-serif", 30))
-        .set_label_area_size(LabelAreaPosition::Left, 80)
-        .set_label_area_size(LabelAreaPosition::Bottom, 50)
-        .build_cartesian_2d(
-            0..losses.len() as u32,
-            min_loss_with_padding..max_loss_with_padding,
-        )?;
+        // Create a chart builder with padded Y-axis range
+        let mut chart = ChartBuilder::on(&root)
+            .caption("Loss Over Epochs", ("sans-serif", 30))
+            .set_label_area_size(LabelAreaPosition::Left, 80)
+            .set_label_area_size(LabelAreaPosition::Bottom, 50)
+            .build_cartesian_2d(0..losses.len() as u32, min_loss..max_loss)?;
 
-    // Draw the chart axes and grid
-    chart
-        .configure_mesh()
-        .y_desc("Loss")
-        .x_desc("Epochs")
-        .draw()?;
+        // Draw the chart axes and grid
+        chart.configure_mesh().y_desc("Loss").x_desc("Epochs").draw()?;
 
-    // Plot the losses as a line
-    chart
-        .draw_series(LineSeries::new(
-            (0..losses.len()).map(|x| (x as u32, losses[x].to_f64().unwrap())),
-            &BLUE,
-        ))?
-        .label("Loss")
-        .legend(move |(x, y)| PathElement::new(vec![(x, y)], &RED));
+        // Plot the losses as a line
+        chart
+            .draw_series(LineSeries::new(
+                (0..losses.len()).map(|x| (x as u32, losses[x])),
+                &BLUE,
+            ))?
+            .label("Loss")
+            .legend(move |(x, y)| PathElement::new(vec![(x, y)], &RED));
 
-    // Draw the legend
-    chart.configure_series_labels().draw()?;
+        // Draw the legend
+        chart.configure_series_labels().draw()?;
 
-    // Format Y-axis labels to handle small floats
-    chart.configure_mesh().y_labels(10).draw()?;
+        // Format Y-axis labels to handle small floats
+        chart.configure_mesh().y_labels(10).draw()?;
 
-    Ok(())
-}
+        root.present()?;
+        Ok(())
+    }
 
-pub fn chart_vector(
-    data: Vec<Vec<Float>>,
-    labels: Option<Vec<String>>,
-    config: Option<ChartConfig>,
-) {
-    let config = config.unwrap_or(ChartConfig::default());
+    let losses: Vec<f64> = losses.iter().map(|l| l.to_f64().unwrap()).collect();
 
-    // Create the drawing area
-    let root = BitMapBackend::new(&config.path, (config.width, config.height)).into_drawing_area();
-    root.fill(&WHITE).unwrap();
+    match output {
+        OutputFormat::Png(path) => {
+            let root = BitMapBackend::new(&path, (800, 600)).into_drawing_area();
+            draw_loss(root, &losses, min_loss_with_padding, max_loss_with_padding)?;
+            Ok(None)
+        }
+        OutputFormat::Svg(path) => {
+            let root = SVGBackend::new(&path, (800, 600)).into_drawing_area();
+            draw_loss(root, &losses, min_loss_with_padding, max_loss_with_padding)?;
+            Ok(None)
+        }
+        OutputFormat::SvgString => {
+            let mut buffer = String::new();
+            {
+                let root = SVGBackend::with_string(&mut buffer, (800, 600)).into_drawing_area();
+                draw_loss(root, &losses, min_loss_with_padding, max_loss_with_padding)?;
+            }
+            Ok(Some(buffer))
+        }
+    }
+}
+
+/// Draw the scatter plot of `data` onto an arbitrary plotters drawing backend.
+///
+/// This is shared by every `OutputFormat` variant of [`chart_vector`] so the
+/// chart-building logic only has to be written once.
+fn draw_chart_vector<DB: DrawingBackend>(
+    root: DrawingArea<DB, Shift>,
+    data: &[Vec<Float>],
+    labels: &Option<Vec<String>>,
+    config: &ChartConfig,
+) -> Result<(), Box<dyn std::error::Error>>
+where
+    DB::ErrorType: 'static,
+{
+    draw_chart_vector_bounded(root, data, labels, config, vector_bounds([data].into_iter()))
+}
 
-    // Calculate min and max for x and y axes
-    let min_x = data
-        .iter()
-        .flat_map(|v| v.iter().step_by(2))
+/// Compute the `(min_x, max_x, min_y, max_y)` axis bounds spanning every point
+/// across one or more embedding snapshots, using the same flattened 2-column
+/// layout as `chart_vector`.
+fn vector_bounds<'a>(
+    snapshots: impl Iterator<Item = &'a [Vec<Float>]> + Clone,
+) -> (Float, Float, Float, Float) {
+    let min_x = snapshots
+        .clone()
+        .flat_map(|data| data.iter().flat_map(|v| v.iter().step_by(2)))
         .cloned()
-        .min_by(|a, b| a.partial_cmp(b).unwrap())
-        .unwrap() as Float;
-    let max_x = data
-        .iter()
-        .flat_map(|v| v.iter().step_by(2))
+        .fold(Float::INFINITY, Float::min);
+    let max_x = snapshots
+        .clone()
+        .flat_map(|data| data.iter().flat_map(|v| v.iter().step_by(2)))
         .cloned()
-        .max_by(|a, b| a.partial_cmp(b).unwrap())
-        .unwrap() as Float;
-    let min_y = data
-        .iter()
-        .flat_map(|v| v.iter().skip(1).step_by(2))
+        .fold(Float::NEG_INFINITY, Float::max);
+    let min_y = snapshots
+        .clone()
+        .flat_map(|data| data.iter().flat_map(|v| v.iter().skip(1).step_by(2)))
         .cloned()
-        .min_by(|a, b| a.partial_cmp(b).unwrap())
-        .unwrap() as Float;
-    let max_y = data
-        .iter()
-        .flat_map(|v| v.iter().skip(1).step_by(2))
+        .fold(Float::INFINITY, Float::min);
+    let max_y = snapshots
+        .flat_map(|data| data.iter().flat_map(|v| v.iter().skip(1).step_by(2)))
         .cloned()
-        .max_by(|a, b| a.partial_cmp(b).unwrap())
-        .unwrap() as Float;
+        .fold(Float::NEG_INFINITY, Float::max);
+    (min_x, max_x, min_y, max_y)
+}
+
+/// Draw the scatter plot of `data` using the given fixed axis bounds, instead
+/// of computing them from `data` itself. This is what lets `animate_embedding`
+/// keep the axes stable across frames.
+fn draw_chart_vector_bounded<DB: DrawingBackend>(
+    root: DrawingArea<DB, Shift>,
+    data: &[Vec<Float>],
+    labels: &Option<Vec<String>>,
+    config: &ChartConfig,
+    (min_x, max_x, min_y, max_y): (Float, Float, Float, Float),
+) -> Result<(), Box<dyn std::error::Error>>
+where
+    DB::ErrorType: 'static,
+{
+    root.fill(&WHITE)?;
 
     // Assign colors to unique labels if provided
     let mut label_colors: Vec<(String, RGBColor)> = Vec::new();
@@ -237,14 +431,35 @@ pub fn chart_vector(
         }
     }
 
+    // `HashSet` iteration order differs from call to call, so hand out the hues
+    // in sorted label order: each label then gets the same color on every call,
+    // which keeps colors stable across the frames of `animate_embedding`.
+    let hues: Vec<RGBColor> = label_colors.iter().map(|(_, c)| *c).collect();
+    let mut sorted_labels: Vec<String> = label_colors.into_iter().map(|(l, _)| l).collect();
+    sorted_labels.sort();
+    let label_colors: Vec<(String, RGBColor)> = sorted_labels.into_iter().zip(hues).collect();
+
+    // Only shrink the margin/label areas on small backends (e.g. the
+    // character grid used by `chart_vector_console`), where the fixed 40/30
+    // defaults below would consume the entire plotting area and leave no room
+    // for data points to map onto. Normal-sized PNG/SVG charts keep the
+    // original 40/30 sizing unchanged.
+    const SMALL_BACKEND_THRESHOLD: u32 = 200;
+    let (backend_w, backend_h) = root.dim_in_pixel();
+    let short_side = backend_w.min(backend_h);
+    let (margin, label_area) = if short_side < SMALL_BACKEND_THRESHOLD {
+        (short_side / 25, short_side / 33)
+    } else {
+        (40, 30)
+    };
+
     // Build chart
     let mut chart = ChartBuilder::on(&root)
-        .caption(config.caption, ("sans-serif", 30))
-        .margin(40)
-        .x_label_area_size(30)
-        .y_label_area_size(30)
-        .build_cartesian_2d(min_x..max_x, min_y..max_y)
-        .unwrap();
+        .caption(&config.caption, ("sans-serif", 30))
+        .margin(margin)
+        .x_label_area_size(label_area)
+        .y_label_area_size(label_area)
+        .build_cartesian_2d(min_x..max_x, min_y..max_y)?;
 
     // Configure and draw the mesh (axes)
     chart
@@ -253,79 +468,119 @@ pub fn chart_vector(
         .y_desc("Y Axis")
         .x_labels(10)
         .y_labels(10)
-        .draw()
-        .unwrap();
+        .draw()?;
+
+    if let RenderMode::Density { resolution } = config.mode {
+        let n = resolution.max(1);
+        let mut grid = vec![0u32; n * n];
+        for values in data {
+            let ix = (((values[0] - min_x) / (max_x - min_x) * n as f64).floor() as isize)
+                .clamp(0, n as isize - 1) as usize;
+            let iy = (((values[1] - min_y) / (max_y - min_y) * n as f64).floor() as isize)
+                .clamp(0, n as isize - 1) as usize;
+            grid[iy * n + ix] += 1;
+        }
+        let max_count = grid.iter().cloned().max().unwrap_or(0).max(1);
+        let cell_w = (max_x - min_x) / n as f64;
+        let cell_h = (max_y - min_y) / n as f64;
+
+        chart.draw_series(
+            grid.iter()
+                .enumerate()
+                .filter(|(_, &count)| count > 0)
+                .map(|(idx, &count)| {
+                    let (ix, iy) = (idx % n, idx / n);
+                    let x0 = min_x + ix as f64 * cell_w;
+                    let y0 = min_y + iy as f64 * cell_h;
+                    // Darker cells mark denser regions
+                    let lightness = 0.9 - 0.6 * (count as f64 / max_count as f64);
+                    let color = HSL {
+                        h: 220.0,
+                        s: 0.7,
+                        l: lightness,
+                    }
+                    .to_rgb();
+                    Rectangle::new(
+                        [(x0, y0), (x0 + cell_w, y0 + cell_h)],
+                        RGBColor(color.0, color.1, color.2).filled(),
+                    )
+                }),
+        )?;
+
+        root.present()?;
+        return Ok(());
+    }
 
     // Store series for later adding to the legend
     let mut series_list: Vec<(String, Vec<(f64, f64)>, RGBColor)> = Vec::new();
 
     // Draw data points and labels
-    chart
-        .draw_series(data.iter().enumerate().map(|(i, values)| {
-            let label = labels
-                .clone()
-                .map(|l| l.get(i).cloned())
-                .flatten()
-                .unwrap_or_else(|| "".into());
-            let color = label_colors
-                .iter()
-                .find(|(l, _)| *l == label)
-                .map(|(_, c)| *c)
-                .unwrap_or(RED);
-
-            // Store series data for the legend
-            if !label.is_empty() {
-                let series_data = series_list.iter_mut().find(|(l, _, _)| *l == label);
-                match series_data {
-                    Some((_, series_points, _)) => series_points.push((values[0], values[1])),
-                    None => series_list.push((label.clone(), vec![(values[0], values[1])], color)),
-                }
+    chart.draw_series(data.iter().enumerate().map(|(i, values)| {
+        let label = labels
+            .clone()
+            .map(|l| l.get(i).cloned())
+            .flatten()
+            .unwrap_or_else(|| "".into());
+        let color = label_colors
+            .iter()
+            .find(|(l, _)| *l == label)
+            .map(|(_, c)| *c)
+            .unwrap_or(RED);
+
+        // Store series data for the legend
+        if !label.is_empty() {
+            let series_data = series_list.iter_mut().find(|(l, _, _)| *l == label);
+            match series_data {
+                Some((_, series_points, _)) => series_points.push((values[0], values[1])),
+                None => series_list.push((label.clone(), vec![(values[0], values[1])], color)),
             }
+        }
 
-            // Draw circle for each point
-            Circle::new(
-                (values[0], values[1]),
-                3,
-                ShapeStyle {
-                    color: color.into(),
-                    filled: false,
-                    stroke_width: 1,
-                },
-            )
-        }))
-        .unwrap();
+        // Draw circle for each point
+        Circle::new(
+            (values[0], values[1]),
+            3,
+            ShapeStyle {
+                color: color.into(),
+                filled: false,
+                stroke_width: 1,
+            },
+        )
+    }))?;
 
     // Add the legend manually
     if labels.is_some() {
         // Sort the series list alphabetically by label
+        // Sort numerically when every label parses as a number, otherwise fall
+        // back to lexicographic order instead of panicking on non-numeric labels
+        let all_numeric = series_list.iter().all(|(l, _, _)| l.parse::<f64>().is_ok());
         series_list.sort_by(|a, b| {
-            let a = a.0.parse::<usize>().unwrap();
-            let b = b.0.parse::<usize>().unwrap();
-            a.cmp(&b)
-            // a.0.cmp(&b.0)
+            if all_numeric {
+                let (x, y) = (a.0.parse::<f64>().unwrap(), b.0.parse::<f64>().unwrap());
+                x.partial_cmp(&y).unwrap_or(std::cmp::Ordering::Equal)
+            } else {
+                a.0.cmp(&b.0)
+            }
         });
 
         let spacing_y = (max_y - min_y) / (series_list.len() * 2) as f64;
 
         // Define the starting position for the legend
         let mut legend_position = (min_x + (max_x - min_x) * 0.8, max_y - (max_y - min_y) * 0.1);
-        // let spacing = 10.0; // Increase the spacing
         let size = 5.0; // Make the circles slightly larger
         let font_size = 15.0;
 
         for (label, _, color) in series_list {
             // Draw a colored circle for each label in the legend
-            chart
-                .draw_series(std::iter::once(Circle::new(
-                    legend_position,
-                    size,
-                    ShapeStyle {
-                        color: color.into(),
-                        filled: true,
-                        stroke_width: 1,
-                    },
-                )))
-                .unwrap();
+            chart.draw_series(std::iter::once(Circle::new(
+                legend_position,
+                size,
+                ShapeStyle {
+                    color: color.into(),
+                    filled: true,
+                    stroke_width: 1,
+                },
+            )))?;
 
             let style = TextStyle {
                 font: ("sans-serif", font_size).into_font(),
@@ -334,19 +589,508 @@ pub fn chart_vector(
             };
 
             // Draw the label text next to the circle
-            chart
-                .draw_series(std::iter::once(Text::new(
-                    label,
-                    (legend_position.0 + spacing_y / 4.0, legend_position.1),
-                    style,
-                )))
-                .unwrap();
+            chart.draw_series(std::iter::once(Text::new(
+                label,
+                (legend_position.0 + spacing_y / 4.0, legend_position.1),
+                style,
+            )))?;
 
             // Move the position for the next legend item downwards
             legend_position.1 -= spacing_y;
         }
     }
 
-    // Save the chart to file
-    root.present().unwrap();
+    // Save the chart
+    root.present()?;
+    Ok(())
+}
+
+/// Plot `data` as a 2D scatter chart using the configured rendering backend.
+///
+/// Returns `Some(svg)` when `config.output` is `OutputFormat::SvgString`, `None` otherwise.
+pub fn chart_vector(
+    data: Vec<Vec<Float>>,
+    labels: Option<Vec<String>>,
+    config: Option<ChartConfig>,
+) -> Option<String> {
+    let config = config.unwrap_or_default();
+
+    match &config.output {
+        OutputFormat::Png(path) => {
+            let root =
+                BitMapBackend::new(path, (config.width, config.height)).into_drawing_area();
+            draw_chart_vector(root, &data, &labels, &config).unwrap();
+            None
+        }
+        OutputFormat::Svg(path) => {
+            let root = SVGBackend::new(path, (config.width, config.height)).into_drawing_area();
+            draw_chart_vector(root, &data, &labels, &config).unwrap();
+            None
+        }
+        OutputFormat::SvgString => {
+            let mut buffer = String::new();
+            {
+                let root = SVGBackend::with_string(&mut buffer, (config.width, config.height))
+                    .into_drawing_area();
+                draw_chart_vector(root, &data, &labels, &config).unwrap();
+            }
+            Some(buffer)
+        }
+    }
+}
+
+/// Draw a 3D scatter plot of `data` onto an arbitrary plotters drawing backend.
+///
+/// Each point is drawn as a small cuboid rather than a `Circle`, since
+/// plotters' 2D point markers don't exist in `Cartesian3d` coordinate space.
+fn draw_chart_vector_3d<DB: DrawingBackend>(
+    root: DrawingArea<DB, Shift>,
+    data: &[Vec<Float>],
+    labels: &Option<Vec<String>>,
+    config: &ChartConfig,
+) -> Result<(), Box<dyn std::error::Error>>
+where
+    DB::ErrorType: 'static,
+{
+    root.fill(&WHITE)?;
+
+    let coord = |v: &[Float], i: usize| v[i] as Float;
+    let min_max = |i: usize| -> (Float, Float) {
+        let min = data
+            .iter()
+            .map(|v| coord(v, i))
+            .fold(Float::INFINITY, Float::min);
+        let max = data
+            .iter()
+            .map(|v| coord(v, i))
+            .fold(Float::NEG_INFINITY, Float::max);
+        (min, max)
+    };
+    let (min_x, max_x) = min_max(0);
+    let (min_y, max_y) = min_max(1);
+    let (min_z, max_z) = min_max(2);
+
+    // Assign colors to unique labels if provided, same scheme as `chart_vector`
+    let mut label_colors: Vec<(String, RGBColor)> = Vec::new();
+    if let Some(labels) = labels.clone() {
+        let unique_labels: Vec<String> = labels
+            .into_iter()
+            .collect::<HashSet<String>>()
+            .into_iter()
+            .collect();
+        for (i, label) in unique_labels.iter().enumerate() {
+            let hue = i as f64 * 360.0 / unique_labels.len() as f64;
+            let color = HSL {
+                h: hue,
+                s: 0.7,
+                l: 0.6,
+            }
+            .to_rgb();
+            label_colors.push((label.clone(), RGBColor(color.0, color.1, color.2)));
+        }
+    }
+
+    let mut chart = ChartBuilder::on(&root)
+        .caption(&config.caption, ("sans-serif", 30))
+        .margin(20)
+        .build_cartesian_3d(min_x..max_x, min_y..max_y, min_z..max_z)?;
+
+    chart.with_projection(|mut p| {
+        p.yaw = config.yaw_3d;
+        p.pitch = config.pitch_3d;
+        p.scale = config.scale_3d;
+        p.into_matrix()
+    });
+
+    chart.configure_axes().draw()?;
+
+    // Half-width of the cuboid used to mark each point, relative to the data extent
+    let half = ((max_x - min_x) + (max_y - min_y) + (max_z - min_z)) / 3.0 * 0.01;
+
+    chart.draw_series(data.iter().enumerate().map(|(i, values)| {
+        let label = labels
+            .clone()
+            .map(|l| l.get(i).cloned())
+            .flatten()
+            .unwrap_or_else(|| "".into());
+        let color = label_colors
+            .iter()
+            .find(|(l, _)| *l == label)
+            .map(|(_, c)| *c)
+            .unwrap_or(RED);
+
+        let (x, y, z) = (values[0], values[1], values[2]);
+        Cubiod::new(
+            [(x - half, y - half, z - half), (x + half, y + half, z + half)],
+            color.filled(),
+            color.stroke_width(1),
+        )
+    }))?;
+
+    root.present()?;
+    Ok(())
+}
+
+/// Plot `data` (3-component points) as a 3D scatter chart using the configured rendering backend.
+///
+/// Camera orientation is taken from `config.yaw_3d`/`pitch_3d`/`scale_3d`.
+/// Returns `Some(svg)` when `config.output` is `OutputFormat::SvgString`, `None` otherwise.
+pub fn chart_vector_3d(
+    data: Vec<Vec<Float>>,
+    labels: Option<Vec<String>>,
+    config: Option<ChartConfig>,
+) -> Option<String> {
+    let config = config.unwrap_or_default();
+
+    match &config.output {
+        OutputFormat::Png(path) => {
+            let root =
+                BitMapBackend::new(path, (config.width, config.height)).into_drawing_area();
+            draw_chart_vector_3d(root, &data, &labels, &config).unwrap();
+            None
+        }
+        OutputFormat::Svg(path) => {
+            let root = SVGBackend::new(path, (config.width, config.height)).into_drawing_area();
+            draw_chart_vector_3d(root, &data, &labels, &config).unwrap();
+            None
+        }
+        OutputFormat::SvgString => {
+            let mut buffer = String::new();
+            {
+                let root = SVGBackend::with_string(&mut buffer, (config.width, config.height))
+                    .into_drawing_area();
+                draw_chart_vector_3d(root, &data, &labels, &config).unwrap();
+            }
+            Some(buffer)
+        }
+    }
+}
+
+/// Plot the 3D chart using the given tensor data (3 components per row) and optional chart configuration
+///
+/// # Arguments
+/// * `data` - A 2D tensor of 3-component data points to plot
+/// * `config` - Optional custom chart configuration
+pub fn chart_tensor_3d<B: Backend>(
+    data: Tensor<B, 2>,
+    labels: Option<Vec<String>>,
+    config: Option<ChartConfig>,
+) -> Option<String> {
+    let data: Vec<Vec<Float>> = convert_tensor_to_vector(data);
+    chart_vector_3d(data, labels, config)
+}
+
+/// Glyph used for a filled cell when rendering to the terminal
+const CONSOLE_GLYPH: char = '●';
+
+/// Error type for [`ConsoleBackend`]; rendering to a character grid can't actually fail.
+#[derive(Debug)]
+struct ConsoleBackendError;
+
+impl fmt::Display for ConsoleBackendError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "console backend error")
+    }
+}
+
+impl std::error::Error for ConsoleBackendError {}
+
+/// A `DrawingBackend` that rasterizes into a character grid and prints it to
+/// the terminal with ANSI truecolor escape codes, instead of writing an image
+/// file. Each cell remembers the color of the last thing drawn into it.
+struct ConsoleBackend {
+    width: u32,
+    height: u32,
+    cells: Vec<Option<(u8, u8, u8)>>,
+}
+
+impl ConsoleBackend {
+    fn new(width: u32, height: u32) -> Self {
+        ConsoleBackend {
+            width,
+            height,
+            cells: vec![None; (width * height) as usize],
+        }
+    }
+}
+
+impl DrawingBackend for ConsoleBackend {
+    type ErrorType = ConsoleBackendError;
+
+    fn get_size(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        Ok(())
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let mut out = String::with_capacity((self.width * self.height) as usize);
+        for y in 0..self.height {
+            for x in 0..self.width {
+                match self.cells[(y * self.width + x) as usize] {
+                    Some((r, g, b)) => {
+                        out.push_str(&format!("\x1b[38;2;{r};{g};{b}m{CONSOLE_GLYPH}\x1b[0m"));
+                    }
+                    None => out.push(' '),
+                }
+            }
+            out.push('\n');
+        }
+        print!("{out}");
+        Ok(())
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: (i32, i32),
+        color: BackendColor,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if point.0 < 0 || point.1 < 0 || point.0 >= self.width as i32 || point.1 >= self.height as i32 {
+            return Ok(());
+        }
+        if color.alpha == 0.0 {
+            return Ok(());
+        }
+        let idx = (point.1 as u32 * self.width + point.0 as u32) as usize;
+        self.cells[idx] = Some(color.rgb);
+        Ok(())
+    }
+}
+
+/// Render `data` as a 2D scatter plot directly to the terminal using ANSI
+/// escape codes, rather than producing a PNG/SVG file.
+///
+/// Reuses the same cartesian layout and HSL label-coloring logic as
+/// `chart_vector`; `config.console_width`/`console_height` control the size
+/// of the character grid. This is meant for a quick sanity check of an
+/// embedding when training over SSH or in CI, where there's no display and
+/// writing image files isn't useful.
+pub fn chart_vector_console(
+    data: Vec<Vec<Float>>,
+    labels: Option<Vec<String>>,
+    config: Option<ChartConfig>,
+) {
+    let config = config.unwrap_or_default();
+    let root =
+        ConsoleBackend::new(config.console_width, config.console_height).into_drawing_area();
+    draw_chart_vector(root, &data, &labels, &config).unwrap();
+}
+
+/// Render the embedding's evolution across training epochs as an animated GIF.
+///
+/// `snapshots` holds one 2D embedding (same layout as `chart_vector`'s `data`)
+/// per captured epoch, in order. Axis ranges are fixed to the global min/max
+/// across every snapshot so points don't jump between frames, and the same
+/// HSL label-coloring path as `chart_vector` is reused for each frame so
+/// class colors stay stable throughout the animation.
+///
+/// `config.output` must be `OutputFormat::Png`, since the GIF is a raster file
+/// written to that path; `OutputFormat::Svg` and `OutputFormat::SvgString`
+/// are rejected with an error.
+pub fn animate_embedding(
+    snapshots: Vec<Vec<Vec<Float>>>,
+    labels: Option<Vec<String>>,
+    frame_delay_ms: u32,
+    config: Option<ChartConfig>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let config = config.unwrap_or_default();
+    // A GIF is inherently raster, so only `Png` makes sense as an output
+    // target here: `Svg` would silently write a raster GIF to a `.svg` path,
+    // and `SvgString` has no file path to write to at all.
+    let path = match &config.output {
+        OutputFormat::Png(path) => path.clone(),
+        OutputFormat::Svg(_) => {
+            return Err("animate_embedding produces a raster GIF and doesn't support \
+                 OutputFormat::Svg; use OutputFormat::Png"
+                .into())
+        }
+        OutputFormat::SvgString => {
+            return Err("animate_embedding requires a file path output, not SvgString".into())
+        }
+    };
+
+    let bounds = vector_bounds(snapshots.iter().map(Vec::as_slice));
+
+    let root = BitMapBackend::gif(&path, (config.width, config.height), frame_delay_ms)?
+        .into_drawing_area();
+
+    // `draw_chart_vector_bounded` already calls `root.present()` once it
+    // finishes drawing (for both the scatter and density branches), which is
+    // what advances the GIF to the next frame; presenting again here would
+    // emit every frame twice, halving playback speed and doubling file size.
+    for data in &snapshots {
+        draw_chart_vector_bounded(root.clone(), data, &labels, &config, bounds)?;
+    }
+
+    Ok(())
+}
+
+/// Map a value to a color along a blue (low) to red (high) hue ramp.
+fn continuous_color(value: f64, min_value: f64, max_value: f64) -> RGBColor {
+    let span = max_value - min_value;
+    let t = if span.abs() > f64::EPSILON {
+        ((value - min_value) / span).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    let hue = 240.0 * (1.0 - t);
+    let color = HSL {
+        h: hue,
+        s: 0.7,
+        l: 0.6,
+    }
+    .to_rgb();
+    RGBColor(color.0, color.1, color.2)
+}
+
+/// Draw a scatter plot colored by a continuous per-point scalar value, with a
+/// vertical colorbar (and tick labels) in the right margin instead of the
+/// discrete per-label circle legend used by `chart_vector`.
+fn draw_chart_vector_continuous<DB: DrawingBackend>(
+    root: DrawingArea<DB, Shift>,
+    data: &[Vec<Float>],
+    values: &[f64],
+    config: &ChartConfig,
+    (min_x, max_x, min_y, max_y): (Float, Float, Float, Float),
+) -> Result<(), Box<dyn std::error::Error>>
+where
+    DB::ErrorType: 'static,
+{
+    root.fill(&WHITE)?;
+
+    let min_v = values.iter().cloned().fold(f64::INFINITY, f64::min);
+    let max_v = values.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+
+    let (plot_area, colorbar_area) =
+        root.split_horizontally((root.dim_in_pixel().0 as f64 * 0.85) as u32);
+
+    let mut chart = ChartBuilder::on(&plot_area)
+        .caption(&config.caption, ("sans-serif", 30))
+        .margin(40)
+        .x_label_area_size(30)
+        .y_label_area_size(30)
+        .build_cartesian_2d(min_x..max_x, min_y..max_y)?;
+
+    chart
+        .configure_mesh()
+        .x_desc("X Axis")
+        .y_desc("Y Axis")
+        .x_labels(10)
+        .y_labels(10)
+        .draw()?;
+
+    chart.draw_series(data.iter().zip(values.iter()).map(|(point, &value)| {
+        Circle::new(
+            (point[0], point[1]),
+            3,
+            ShapeStyle {
+                color: continuous_color(value, min_v, max_v).into(),
+                filled: false,
+                stroke_width: 1,
+            },
+        )
+    }))?;
+
+    // Draw the colorbar as a stack of thin rectangles interpolated from min to max.
+    // Guard against `min_v == max_v` (e.g. a single value or all-equal values),
+    // which would otherwise build a zero-height axis range, same as `continuous_color`.
+    let colorbar_max_v = if (max_v - min_v).abs() > f64::EPSILON {
+        max_v
+    } else {
+        min_v + 1.0
+    };
+
+    let mut colorbar = ChartBuilder::on(&colorbar_area)
+        .margin(40)
+        .y_label_area_size(50)
+        .build_cartesian_2d(0.0..1.0, min_v..colorbar_max_v)?;
+
+    colorbar
+        .configure_mesh()
+        .disable_x_mesh()
+        .disable_x_axis()
+        .y_labels(5)
+        .y_desc("Value")
+        .draw()?;
+
+    const BAR_STEPS: usize = 100;
+    colorbar.draw_series((0..BAR_STEPS).map(|i| {
+        let v0 = min_v + (colorbar_max_v - min_v) * (i as f64 / BAR_STEPS as f64);
+        let v1 = min_v + (colorbar_max_v - min_v) * ((i + 1) as f64 / BAR_STEPS as f64);
+        Rectangle::new(
+            [(0.0, v0), (1.0, v1)],
+            continuous_color((v0 + v1) / 2.0, min_v, max_v).filled(),
+        )
+    }))?;
+
+    root.present()?;
+    Ok(())
+}
+
+/// Plot `data` as a 2D scatter chart colored by a continuous scalar `values`
+/// per point (e.g. density, confidence, a regression target), with a
+/// colorbar instead of a discrete label legend.
+///
+/// Falls back to the categorical `chart_vector` path when `values` is `None`.
+/// Returns `Some(svg)` when `config.output` is `OutputFormat::SvgString`, `None` otherwise.
+pub fn chart_vector_continuous(
+    data: Vec<Vec<Float>>,
+    values: Option<Vec<f64>>,
+    config: Option<ChartConfig>,
+) -> Option<String> {
+    let config = config.unwrap_or_default();
+
+    let values = match values {
+        Some(values) => values,
+        None => return chart_vector(data, None, Some(config)),
+    };
+    assert_eq!(
+        values.len(),
+        data.len(),
+        "chart_vector_continuous: values.len() ({}) must match data.len() ({})",
+        values.len(),
+        data.len()
+    );
+
+    let bounds = vector_bounds([data.as_slice()].into_iter());
+
+    match &config.output {
+        OutputFormat::Png(path) => {
+            let root =
+                BitMapBackend::new(path, (config.width, config.height)).into_drawing_area();
+            draw_chart_vector_continuous(root, &data, &values, &config, bounds).unwrap();
+            None
+        }
+        OutputFormat::Svg(path) => {
+            let root = SVGBackend::new(path, (config.width, config.height)).into_drawing_area();
+            draw_chart_vector_continuous(root, &data, &values, &config, bounds).unwrap();
+            None
+        }
+        OutputFormat::SvgString => {
+            let mut buffer = String::new();
+            {
+                let root = SVGBackend::with_string(&mut buffer, (config.width, config.height))
+                    .into_drawing_area();
+                draw_chart_vector_continuous(root, &data, &values, &config, bounds).unwrap();
+            }
+            Some(buffer)
+        }
+    }
+}
+
+/// Plot the 2D chart using tensor data colored by a continuous scalar value per point.
+///
+/// # Arguments
+/// * `data` - A 2D tensor of data points to plot
+/// * `values` - Optional per-point scalar values to color by; falls back to an uncolored scatter when `None`
+/// * `config` - Optional custom chart configuration
+pub fn chart_tensor_continuous<B: Backend>(
+    data: Tensor<B, 2>,
+    values: Option<Vec<f64>>,
+    config: Option<ChartConfig>,
+) -> Option<String> {
+    let data: Vec<Vec<Float>> = convert_tensor_to_vector(data);
+    chart_vector_continuous(data, values, config)
 }